@@ -0,0 +1,55 @@
+use super::CodeGen;
+use crate::naming::Casing;
+use std::fmt::{self, Write};
+
+/// Emits C# classes with auto-implemented properties.
+/// eg. `public int id { get; set; }`
+pub struct CSharp;
+
+impl CodeGen for CSharp {
+    fn name(&self) -> &'static str { "csharp" }
+
+    fn string_type(&self) -> &'static str { "string" }
+    fn int_type(&self) -> &'static str { "int" }
+    fn uint_type(&self) -> &'static str { "uint" }
+    fn float_type(&self) -> &'static str { "float" }
+    fn bool_type(&self) -> &'static str { "bool" }
+    fn object_type(&self) -> &'static str { "object" }
+
+    fn array_type(&self, element: &str) -> String {
+        format!("{}[]", element)
+    }
+
+    fn begin_class(&self, out: &mut String, name: &str) -> fmt::Result {
+        writeln!(out, "public class {}\n{{", name)
+    }
+
+    fn write_property(&self, out: &mut String, name: &str, typename: &str, indent: &str) -> fmt::Result {
+        writeln!(out, "{}public {} {} {{ get; set; }}", indent, typename, name)
+    }
+
+    fn write_key_annotation(&self, out: &mut String, indent: &str, original_key: &str) -> fmt::Result {
+        writeln!(out, "{}[JsonPropertyName(\"{}\")]", indent, original_key)
+    }
+
+    fn end_class(&self, out: &mut String) -> fmt::Result {
+        writeln!(out, "}}")
+    }
+
+    fn date_time_type(&self) -> &'static str { "DateTime" }
+    fn uuid_type(&self) -> &'static str { "Guid" }
+
+    fn nullable_type(&self, inner: &str) -> String {
+        format!("{}?", inner)
+    }
+
+    fn write_enum(&self, out: &mut String, name: &str, variants: &[(String, String)], indent: &str) -> fmt::Result {
+        writeln!(out, "public enum {}\n{{", name)?;
+        for (identifier, _) in variants {
+            writeln!(out, "{}{},", indent, identifier)?;
+        }
+        writeln!(out, "}}")
+    }
+
+    fn default_casing(&self) -> Casing { Casing::Pascal }
+}
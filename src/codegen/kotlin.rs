@@ -0,0 +1,55 @@
+use super::CodeGen;
+use crate::naming::Casing;
+use std::fmt::{self, Write};
+
+/// Emits Kotlin data classes.
+/// eg. `val id: Long,`
+pub struct Kotlin;
+
+impl CodeGen for Kotlin {
+    fn name(&self) -> &'static str { "kotlin" }
+
+    fn string_type(&self) -> &'static str { "String" }
+    fn int_type(&self) -> &'static str { "Long" }
+    fn uint_type(&self) -> &'static str { "Long" }
+    fn float_type(&self) -> &'static str { "Double" }
+    fn bool_type(&self) -> &'static str { "Boolean" }
+    fn object_type(&self) -> &'static str { "Any" }
+
+    fn array_type(&self, element: &str) -> String {
+        format!("List<{}>", element)
+    }
+
+    fn begin_class(&self, out: &mut String, name: &str) -> fmt::Result {
+        writeln!(out, "data class {}(", name)
+    }
+
+    fn write_property(&self, out: &mut String, name: &str, typename: &str, indent: &str) -> fmt::Result {
+        writeln!(out, "{}val {}: {},", indent, name, typename)
+    }
+
+    fn write_key_annotation(&self, out: &mut String, indent: &str, original_key: &str) -> fmt::Result {
+        writeln!(out, "{}@SerializedName(\"{}\")", indent, original_key)
+    }
+
+    fn end_class(&self, out: &mut String) -> fmt::Result {
+        writeln!(out, ")")
+    }
+
+    fn date_time_type(&self) -> &'static str { "java.time.OffsetDateTime" }
+    fn uuid_type(&self) -> &'static str { "java.util.UUID" }
+
+    fn nullable_type(&self, inner: &str) -> String {
+        format!("{}?", inner)
+    }
+
+    fn write_enum(&self, out: &mut String, name: &str, variants: &[(String, String)], indent: &str) -> fmt::Result {
+        writeln!(out, "enum class {} {{", name)?;
+        for (identifier, _) in variants {
+            writeln!(out, "{}{},", indent, identifier)?;
+        }
+        writeln!(out, "}}")
+    }
+
+    fn default_casing(&self) -> Casing { Casing::Camel }
+}
@@ -0,0 +1,480 @@
+//! Language backends for `ClassGenerator`.
+//!
+//! The AST walk (BFS over nested objects) lives here and is shared by every
+//! target language. Each backend only supplies the bits that actually vary
+//! from language to language: primitive type names, array syntax, and the
+//! class/property boilerplate.
+
+mod csharp;
+mod kotlin;
+mod rust;
+mod typescript;
+
+pub use csharp::CSharp;
+pub use kotlin::Kotlin;
+pub use rust::Rust;
+pub use typescript::TypeScript;
+
+use crate::naming::Casing;
+use serde_json::Value;
+use std::fmt::{self, Write, Error};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+// ----------------------------------------------------------------------------
+
+/// A target language for the generated classes.
+///
+/// Implementors supply the primitive type table and the class/property
+/// syntax; `ClassGenerator` drives the AST walk and calls into the backend
+/// wherever the output would otherwise be language-specific.
+pub trait CodeGen {
+    /// Short name used to select this backend from `-l` and to label output
+    /// when multiple languages are emitted at once.
+    fn name(&self) -> &'static str;
+
+    fn string_type(&self) -> &'static str;
+    fn int_type(&self) -> &'static str;
+    fn uint_type(&self) -> &'static str;
+    fn float_type(&self) -> &'static str;
+    fn bool_type(&self) -> &'static str;
+
+    /// Type used when samples can't be unified into anything more specific
+    /// (eg. an empty array, or an array mixing strings and objects).
+    fn object_type(&self) -> &'static str;
+
+    /// Wrap `element` one array dimension deeper, eg. "int" -> "int[]".
+    fn array_type(&self, element: &str) -> String;
+
+    /// Write the opening declaration for a class named `name`, up to and
+    /// including the line that opens the property list.
+    fn begin_class(&self, out: &mut String, name: &str) -> fmt::Result;
+
+    /// Write a single property line, indented by `indent` (one level's
+    /// worth of spaces, per `--indent`).
+    fn write_property(&self, out: &mut String, name: &str, typename: &str, indent: &str) -> fmt::Result;
+
+    /// Write an attribute/annotation recording `original_key` above a
+    /// property whose emitted name differs from it (eg. `--casing` changed
+    /// its case). Languages with no such mechanism can leave this as a
+    /// no-op.
+    fn write_key_annotation(&self, _out: &mut String, _indent: &str, _original_key: &str) -> fmt::Result {
+        Ok(())
+    }
+
+    /// Close the property list opened by `begin_class`.
+    fn end_class(&self, out: &mut String) -> fmt::Result;
+
+    /// Type used for a schema `"format": "date-time"` string.
+    fn date_time_type(&self) -> &'static str;
+
+    /// Type used for a schema `"format": "uuid"` string.
+    fn uuid_type(&self) -> &'static str;
+
+    /// Wrap `inner` to mark it as nullable/optional, eg. "int" -> "int?".
+    fn nullable_type(&self, inner: &str) -> String;
+
+    /// Write a standalone enum declaration, eg. from a schema `"enum"` array.
+    /// Each variant is `(identifier, raw_value)`: `identifier` is a
+    /// sanitized, valid identifier (eg. "DateTime"); `raw_value` is the
+    /// original schema string it stands for (eg. "date-time").
+    fn write_enum(&self, out: &mut String, name: &str, variants: &[(String, String)], indent: &str) -> fmt::Result;
+
+    /// The `--casing` value to apply to property names when the user didn't
+    /// pass `--casing` explicitly, eg. snake_case for Rust, camelCase for
+    /// Kotlin/TypeScript.
+    fn default_casing(&self) -> Casing;
+}
+
+/// Resolves a backend from a `-l`/`--lang` token, eg. "cs", "ts", "rust".
+pub fn lookup(name: &str) -> Option<Box<dyn CodeGen>> {
+    match name.trim().to_ascii_lowercase().as_str() {
+        "cs" | "csharp" | "c#" => Some(Box::new(CSharp)),
+        "ts" | "typescript" => Some(Box::new(TypeScript)),
+        "rust" | "rs" => Some(Box::new(Rust)),
+        "kotlin" | "kt" => Some(Box::new(Kotlin)),
+        _ => None,
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Represents the inferred type of a JSON value.
+/// Primitive  :  string | int | uint | float | bool
+/// Custom     :  user-defined | primitive | []
+enum CSharpType {
+    Primitive(&'static str), // eg. "float"
+    Custom(String),          // eg. "HttpResponse[]"
+}
+
+// make printable/writable
+impl fmt::Display for CSharpType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CSharpType::Primitive(typename) => write!(f, "{}", typename),
+            CSharpType::Custom(typename) => write!(f, "{}", typename),
+        }
+    }
+}
+
+// define conversion to string slice
+impl CSharpType {
+    fn as_str(&self) -> &str {
+        match *self {
+            CSharpType::Primitive(s) => s,
+            CSharpType::Custom(ref s) => s
+        }
+    }
+}
+
+/// An inferred type together with whether it should be nullable, eg. a
+/// property that was `Null`/missing in some but not all unified samples.
+struct Inferred {
+    ty: CSharpType,
+    nullable: bool,
+}
+
+impl Inferred {
+    fn non_null(ty: CSharpType) -> Self {
+        Self { ty, nullable: false }
+    }
+
+    /// Render the final type string, applying the backend's nullable
+    /// wrapping (eg. "int" -> "int?") when needed.
+    fn render(&self, backend: &dyn CodeGen) -> String {
+        if self.nullable {
+            backend.nullable_type(self.ty.as_str())
+        } else {
+            self.ty.as_str().to_owned()
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Canonical shape of a class: its fields' (name, type, nullable) triples,
+/// sorted so that two objects with the same fields in different orders
+/// compare equal.
+type Signature = Vec<(String, String, bool)>;
+
+pub struct ClassGenerator {
+    // queue of classes that are to be generated
+    // each tuple is (<class name>, <already-inferred fields>)
+    todos: VecDeque<(String, Vec<(String, Inferred)>)>,
+    // shape signature -> the class name already assigned to it, so a
+    // second object with an identical shape reuses the same class instead
+    // of generating a duplicate.
+    shapes: HashMap<Signature, String>,
+    // class names already handed out, so a different shape that wants an
+    // already-taken name gets disambiguated instead of colliding with it.
+    used_names: HashSet<String>,
+}
+
+impl ClassGenerator {
+    pub fn new() -> Self {
+        let mut used_names = HashSet::new();
+        used_names.insert("Root".to_owned());
+        Self { todos: VecDeque::new(), shapes: HashMap::new(), used_names }
+    }
+
+    /// Generates the classes corresponding to the given AST using `backend`,
+    /// naming properties per `casing` and indenting property/enum lines by
+    /// `indent`.
+    /// Returns the classes as a string.
+    pub fn generate(&mut self, ast: &Value, backend: &dyn CodeGen, casing: Casing, indent: &str) -> Result<String, Error> {
+        // output builder
+        let mut out = String::new();
+        // root of the AST
+        let root = ast.as_object().ok_or(fmt::Error)?;
+        let root_fields = self.infer_object(root, backend);
+
+        // generate the Root class. This corresponds to the base { ... } of the JSON.
+        backend.begin_class(&mut out, "Root")?;
+        Self::write_properties(&root_fields, &mut out, backend, casing, indent)?;
+        backend.end_class(&mut out)?;
+
+        // generate all other classes.
+        while let Some((class, fields)) = self.todos.pop_front() {
+            writeln!(&mut out)?;
+            backend.begin_class(&mut out, &class)?;
+            Self::write_properties(&fields, &mut out, backend, casing, indent)?;
+            backend.end_class(&mut out)?;
+        }
+
+        Ok(out)
+    }
+
+    /// Write already-inferred class properties.
+    /// eg. "public int id { get; set; }"
+    ///
+    /// `casing` can collapse distinct JSON keys onto the same identifier
+    /// (eg. "Data" and "data" both becoming "Data"), so emitted names are
+    /// disambiguated per class the same way colliding class names are
+    /// (`Data`, `Data2`, ...); the annotation still records the original key,
+    /// so the wire format is unaffected.
+    fn write_properties(fields: &[(String, Inferred)], out: &mut String, backend: &dyn CodeGen, casing: Casing, indent: &str) -> Result<(), Error> {
+        let mut used_names = HashSet::new();
+        for (name, inferred) in fields {
+            let typename = inferred.render(backend);
+            let varname = crate::naming::disambiguate(&mut used_names, &casing.apply(name));
+            if varname != *name {
+                backend.write_key_annotation(out, indent, name)?;
+            }
+            backend.write_property(out, &varname, &typename, indent)?;
+        }
+        Ok(())
+    }
+
+    /// Reserves a class for `fields`, reusing an already-generated class if
+    /// an identical shape was queued before, and disambiguating `desired_name`
+    /// (`Data`, `Data2`, ...) if a *different* shape already claimed it.
+    /// Returns the class name to reference; only enqueues `fields` for
+    /// generation the first time a shape is seen.
+    fn intern_shape(&mut self, desired_name: &str, fields: Vec<(String, Inferred)>) -> String {
+        let signature = Self::signature_of(&fields);
+        if let Some(existing) = self.shapes.get(&signature) {
+            return existing.clone();
+        }
+
+        let name = crate::naming::disambiguate(&mut self.used_names, desired_name);
+        self.shapes.insert(signature, name.clone());
+        self.todos.push_back((name.clone(), fields));
+        name
+    }
+
+    /// Computes the canonical signature of a class's fields.
+    fn signature_of(fields: &[(String, Inferred)]) -> Signature {
+        let mut signature: Signature = fields.iter()
+            .map(|(name, inferred)| (name.clone(), inferred.ty.as_str().to_owned(), inferred.nullable))
+            .collect();
+        signature.sort();
+        signature
+    }
+
+    /// Infer the type of every property of a JSON object.
+    fn infer_object(&mut self, obj: &serde_json::Map<String, Value>, backend: &dyn CodeGen) -> Vec<(String, Inferred)> {
+        obj.iter()
+            .map(|(name, value)| (name.clone(), self.infer_value(name, value, backend)))
+            .collect()
+    }
+
+    /// Infer the concrete type of a single JSON value appearing under
+    /// `field_name`, as rendered by `backend`.
+    fn infer_value(&mut self, field_name: &str, value: &Value, backend: &dyn CodeGen) -> Inferred {
+        match value {
+            // A bare `null` (no sibling samples to unify against) has no
+            // type information to go on; fall back to a nullable string
+            // rather than panicking.
+            Value::Null => Inferred { ty: CSharpType::Primitive(backend.string_type()), nullable: true },
+            Value::String(_) => Inferred::non_null(CSharpType::Primitive(backend.string_type())),
+            Value::Number(num) => {
+                let ty = match num {
+                    _ if num.is_i64() => backend.int_type(),
+                    _ if num.is_f64() => backend.float_type(),
+                    _ => backend.uint_type(),
+                };
+                Inferred::non_null(CSharpType::Primitive(ty))
+            },
+            Value::Bool(_) => Inferred::non_null(CSharpType::Primitive(backend.bool_type())),
+            Value::Array(elements) => {
+                if elements.is_empty() {
+                    Inferred::non_null(CSharpType::Custom(backend.array_type(backend.object_type())))
+                } else {
+                    let refs: Vec<&Value> = elements.iter().collect();
+                    let element = self.unify(field_name, &refs, backend);
+                    let rendered = element.render(backend);
+                    Inferred::non_null(CSharpType::Custom(backend.array_type(&rendered)))
+                }
+            },
+            Value::Object(obj) => {
+                let desired_name = crate::naming::pascal_case(field_name);
+                let fields = self.infer_object(obj, backend);
+                // reuses an existing class for this shape, or enqueues a
+                // (possibly renamed) new one if the shape or name is new.
+                let class_name = self.intern_shape(&desired_name, fields);
+                Inferred::non_null(CSharpType::Custom(class_name))
+            },
+        }
+    }
+
+    /// Unify a bag of same-slot JSON values (eg. the elements of one array,
+    /// or one key's value across several merged objects) into a single
+    /// type. Any `Null`/missing sample makes the result nullable.
+    fn unify(&mut self, field_name: &str, values: &[&Value], backend: &dyn CodeGen) -> Inferred {
+        let any_null = values.iter().any(|v| v.is_null());
+        let present: Vec<&Value> = values.iter().copied().filter(|v| !v.is_null()).collect();
+
+        let mut inferred = if present.is_empty() {
+            // every sample was null/missing
+            Inferred::non_null(CSharpType::Primitive(backend.string_type()))
+        } else if present.iter().all(|v| v.is_string()) {
+            Inferred::non_null(CSharpType::Primitive(backend.string_type()))
+        } else if present.iter().all(|v| v.is_boolean()) {
+            Inferred::non_null(CSharpType::Primitive(backend.bool_type()))
+        } else if present.iter().all(|v| v.is_number()) {
+            let any_float = present.iter().any(|v| v.is_f64());
+            let ty = if any_float { backend.float_type() } else { backend.int_type() };
+            Inferred::non_null(CSharpType::Primitive(ty))
+        } else if present.iter().all(|v| v.is_array()) {
+            let inner: Vec<&Value> = present.iter()
+                .flat_map(|v| match v {
+                    Value::Array(a) => a.iter(),
+                    _ => unreachable!(),
+                })
+                .collect();
+            let element = self.unify(field_name, &inner, backend);
+            let rendered = element.render(backend);
+            Inferred::non_null(CSharpType::Custom(backend.array_type(&rendered)))
+        } else if present.iter().all(|v| v.is_object()) {
+            let desired_name = crate::naming::pascal_case(field_name);
+            let fields = self.merge_objects(&present, backend);
+            let class_name = self.intern_shape(&desired_name, fields);
+            Inferred::non_null(CSharpType::Custom(class_name))
+        } else {
+            // irreconcilable samples, eg. a string mixed with an object
+            Inferred::non_null(CSharpType::Custom(backend.object_type().to_owned()))
+        };
+
+        if any_null {
+            inferred.nullable = true;
+        }
+        inferred
+    }
+
+    /// Merge several objects' shapes into one synthetic class: the union of
+    /// their keys, with each key's type unified across every sample that
+    /// has it. A key missing from some samples is marked nullable.
+    fn merge_objects(&mut self, objects: &[&Value], backend: &dyn CodeGen) -> Vec<(String, Inferred)> {
+        let mut keys: Vec<&str> = Vec::new();
+        for obj in objects {
+            if let Value::Object(map) = obj {
+                for key in map.keys() {
+                    if !keys.contains(&key.as_str()) {
+                        keys.push(key.as_str());
+                    }
+                }
+            }
+        }
+
+        keys.into_iter().map(|key| {
+            let mut values: Vec<&Value> = Vec::new();
+            let mut missing_somewhere = false;
+            for obj in objects {
+                if let Value::Object(map) = obj {
+                    match map.get(key) {
+                        Some(v) => values.push(v),
+                        None => missing_somewhere = true,
+                    }
+                }
+            }
+
+            let mut inferred = self.unify(key, &values, backend);
+            if missing_somewhere {
+                inferred.nullable = true;
+            }
+            (key.to_owned(), inferred)
+        }).collect()
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+    use std::fs;
+
+    #[test]
+    fn test_array_unification_and_nullability() {
+        let ast = json!({
+            "values": [1, null, 2],
+            "tags": [],
+            "mixed": ["a string", {"not": "a string"}],
+        });
+
+        let mut generator = ClassGenerator::new();
+        let output = generator.generate(&ast, &CSharp, Casing::Pascal, "    ")
+            .expect("Failed to generate classes.");
+
+        // a null among the samples makes the unified element type nullable
+        assert!(output.contains("public int?[] Values { get; set; }"));
+        // an empty array has no samples to unify, so it falls back to the
+        // backend's object type rather than hardcoding a C#-only literal
+        assert!(output.contains("public object[] Tags { get; set; }"));
+        // irreconcilable samples (string vs. object) also fall back to object
+        assert!(output.contains("public object[] Mixed { get; set; }"));
+    }
+
+    #[test]
+    fn test_dedup_and_collision() {
+        let ast = json!({
+            "a": {"x": 1},
+            "b": {"x": 2},
+            "Data": {"x": "hello"},
+            "data": {"y": "z"},
+        });
+
+        let mut generator = ClassGenerator::new();
+        let output = generator.generate(&ast, &CSharp, Casing::Pascal, "    ")
+            .expect("Failed to generate classes.");
+
+        // "a" and "b" have identical shapes, so they share one generated
+        // class instead of producing two structurally-identical ones
+        assert!(output.contains("public A A { get; set; }"));
+        assert!(output.contains("public A B { get; set; }"));
+        assert_eq!(output.matches("public class A").count(), 1);
+
+        // "Data" and "data" both pascal-case to the same desired name, but
+        // have different shapes, so the second gets disambiguated
+        assert!(output.contains("public class Data"));
+        assert!(output.contains("public class Data2"));
+
+        // the "Data"/"data" keys collide on the emitted property name too
+        // (not just the class name) -- the second property must also be
+        // disambiguated, or the Root class would declare "Data" twice
+        assert!(output.contains("public Data Data { get; set; }"));
+        assert!(output.contains("public Data2 Data2 { get; set; }"));
+        assert_eq!(output.matches("public Data Data").count(), 1);
+    }
+
+    #[test]
+    fn test_rust_backend_uses_rust_conventions() {
+        use crate::codegen::Rust;
+
+        let ast = json!({
+            "id": 1,
+            "createdAt": "2020-01-01T00:00:00Z",
+            "address": {"city": "Seattle"},
+        });
+
+        let mut generator = ClassGenerator::new();
+        let output = generator.generate(&ast, &Rust, Casing::Snake, "    ")
+            .expect("Failed to generate classes.");
+
+        assert!(output.contains("pub struct Root {"));
+        assert!(output.contains("pub created_at: String,"));
+        assert!(output.contains("pub address: Address,"));
+        assert!(output.contains("pub struct Address {"));
+        assert!(output.contains("pub city: String,"));
+    }
+
+    #[test]
+    fn test_weather() {
+        let resources = env!("CARGO_MANIFEST_DIR").to_owned() + "/resources/";
+        let input_path = resources.clone() + "weather.json";
+        let answer_path = resources + "weather.cs";
+
+        let ast =
+            fs::read_to_string(input_path)
+            .map(|weather_json|
+                serde_json::from_str(&weather_json).unwrap()
+            )
+            .unwrap();
+
+        let mut generator = ClassGenerator::new();
+        let output = generator.generate(&ast, &CSharp, Casing::Pascal, "    ").expect("Failed to generate classes.");
+
+        let answer = fs::read_to_string(answer_path).unwrap();
+        assert!(output.eq(&answer));
+    }
+}
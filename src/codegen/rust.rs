@@ -0,0 +1,55 @@
+use super::CodeGen;
+use crate::naming::Casing;
+use std::fmt::{self, Write};
+
+/// Emits Rust structs with public fields.
+/// eg. `pub id: i64,`
+pub struct Rust;
+
+impl CodeGen for Rust {
+    fn name(&self) -> &'static str { "rust" }
+
+    fn string_type(&self) -> &'static str { "String" }
+    fn int_type(&self) -> &'static str { "i64" }
+    fn uint_type(&self) -> &'static str { "u64" }
+    fn float_type(&self) -> &'static str { "f64" }
+    fn bool_type(&self) -> &'static str { "bool" }
+    fn object_type(&self) -> &'static str { "serde_json::Value" }
+
+    fn array_type(&self, element: &str) -> String {
+        format!("Vec<{}>", element)
+    }
+
+    fn begin_class(&self, out: &mut String, name: &str) -> fmt::Result {
+        writeln!(out, "pub struct {} {{", name)
+    }
+
+    fn write_property(&self, out: &mut String, name: &str, typename: &str, indent: &str) -> fmt::Result {
+        writeln!(out, "{}pub {}: {},", indent, name, typename)
+    }
+
+    fn write_key_annotation(&self, out: &mut String, indent: &str, original_key: &str) -> fmt::Result {
+        writeln!(out, "{}#[serde(rename = \"{}\")]", indent, original_key)
+    }
+
+    fn end_class(&self, out: &mut String) -> fmt::Result {
+        writeln!(out, "}}")
+    }
+
+    fn date_time_type(&self) -> &'static str { "String" }
+    fn uuid_type(&self) -> &'static str { "String" }
+
+    fn nullable_type(&self, inner: &str) -> String {
+        format!("Option<{}>", inner)
+    }
+
+    fn write_enum(&self, out: &mut String, name: &str, variants: &[(String, String)], indent: &str) -> fmt::Result {
+        writeln!(out, "pub enum {} {{", name)?;
+        for (identifier, _) in variants {
+            writeln!(out, "{}{},", indent, identifier)?;
+        }
+        writeln!(out, "}}")
+    }
+
+    fn default_casing(&self) -> Casing { Casing::Snake }
+}
@@ -0,0 +1,57 @@
+use super::CodeGen;
+use crate::naming::Casing;
+use std::fmt::{self, Write};
+
+/// Emits TypeScript interfaces.
+/// eg. `id: number;`
+pub struct TypeScript;
+
+impl CodeGen for TypeScript {
+    fn name(&self) -> &'static str { "ts" }
+
+    fn string_type(&self) -> &'static str { "string" }
+    fn int_type(&self) -> &'static str { "number" }
+    fn uint_type(&self) -> &'static str { "number" }
+    fn float_type(&self) -> &'static str { "number" }
+    fn bool_type(&self) -> &'static str { "boolean" }
+    fn object_type(&self) -> &'static str { "any" }
+
+    fn array_type(&self, element: &str) -> String {
+        // a union element (eg. from nullable_type) must be parenthesized,
+        // or "[]" would bind to its last member only
+        if element.contains('|') {
+            format!("({})[]", element)
+        } else {
+            format!("{}[]", element)
+        }
+    }
+
+    fn begin_class(&self, out: &mut String, name: &str) -> fmt::Result {
+        writeln!(out, "export interface {} {{", name)
+    }
+
+    fn write_property(&self, out: &mut String, name: &str, typename: &str, indent: &str) -> fmt::Result {
+        writeln!(out, "{}{}: {};", indent, name, typename)
+    }
+
+    fn end_class(&self, out: &mut String) -> fmt::Result {
+        writeln!(out, "}}")
+    }
+
+    fn date_time_type(&self) -> &'static str { "Date" }
+    fn uuid_type(&self) -> &'static str { "string" }
+
+    fn nullable_type(&self, inner: &str) -> String {
+        format!("{} | null", inner)
+    }
+
+    fn write_enum(&self, out: &mut String, name: &str, variants: &[(String, String)], indent: &str) -> fmt::Result {
+        writeln!(out, "export enum {} {{", name)?;
+        for (identifier, raw_value) in variants {
+            writeln!(out, "{}{} = \"{}\",", indent, identifier, raw_value)?;
+        }
+        writeln!(out, "}}")
+    }
+
+    fn default_casing(&self) -> Casing { Casing::Camel }
+}
@@ -1,56 +1,40 @@
-//! Generate C# classes from the given JSON
+//! Generate classes from the given JSON, in one or more target languages.
 //! Author: Daniel Mar
-//! TODO: handle name collisions?
-//! TODO: map Value::Null to Nullable<string>?
-
-/// General Strategy:
-/// 1. Use the serde crate to parse JSON into an abstract syntax tree
-///
-/// 2. Generate the Root class.
-/// - find property names by descending the AST node until a
-///   concrete type is found.
-/// - if a concrete type is itself an object/custom type, add that
-///   object's node to a queue (for later processing).
-///
-/// 3. Generate the remaining classes by repeating (2) on the queued nodes.
-/// -  a la breadth-first search
+//!
+//! General Strategy:
+//! 1. Use the serde crate to parse JSON into an abstract syntax tree
+//!
+//! 2. Generate the Root class.
+//! - find property names by descending the AST node until a
+//!   concrete type is found.
+//! - if a concrete type is itself an object/custom type, add that
+//!   object's node to a queue (for later processing).
+//!
+//! 3. Generate the remaining classes by repeating (2) on the queued nodes.
+//! -  a la breadth-first search
+//!
+//! Array properties are inferred by unifying every element's type (not
+//! just the first), and arrays of objects are merged into one synthetic
+//! class over the union of their keys. Two objects with the same shape
+//! share a single generated class, and a name already taken by a
+//! different shape gets disambiguated (`Data`, `Data2`, ...).
+//!
+//! The class/property syntax itself is language-specific and lives behind
+//! the `CodeGen` trait in the `codegen` module, so the same walk can target
+//! C#, TypeScript, Rust, or Kotlin.
+
+mod codegen;
+mod naming;
+mod schema;
 
 use serde_json::{Value, Map};
-use std::fmt::{Write, Error};
-use std::collections::VecDeque;
-use std::{fmt, io, fs};
+use std::{io, fs};
 use clap::{Arg, App};
 use std::io::Read;
 
-// ----------------------------------------------------------------------------
-
-/// Represents a CSharpType
-/// Primitive  :  string | int | uint | float | bool
-/// Custom     :  user-defined | primitive | []
-enum CSharpType {
-    Primitive(&'static str), // eg. "float"
-    Custom(String),          // eg. "HttpResponse[]"
-}
-
-// make printable/writable
-impl fmt::Display for CSharpType {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            CSharpType::Primitive(typename) => write!(f, "{}", typename),
-            CSharpType::Custom(typename) => write!(f, "{}", typename),
-        }
-    }
-}
-
-// define conversion to string slice
-impl CSharpType {
-    fn as_str(&self) -> &str {
-        match *self {
-            CSharpType::Primitive(s) => s,
-            CSharpType::Custom(ref s) => s
-        }
-    }
-}
+use codegen::ClassGenerator;
+use naming::Casing;
+use schema::SchemaGenerator;
 
 // ----------------------------------------------------------------------------
 
@@ -59,169 +43,94 @@ fn main() {
     let matches = App::new("paste_json")
         .version("0.1")
         .author("Daniel M. <dmar@uw.edu>")
-        .about("Generates C# classes to represent the given JSON.")
+        .about("Generates classes to represent the given JSON.")
         .after_help(r#"EXAMPLES:
         (1)   paste_json weather.json
 
-        (2)   cat weather.json | paste_json"#)
+        (2)   cat weather.json | paste_json
+
+        (3)   paste_json -l ts,rust weather.json
+
+        (4)   paste_json --schema weather.schema.json
+
+        (5)   paste_json --casing snake --indent 2 weather.json"#)
         .arg(Arg::with_name("file")
             .help("The file containing the JSON object")
             .required(false))
+        .arg(Arg::with_name("lang")
+            .short("l")
+            .long("lang")
+            .value_name("LANG")
+            .takes_value(true)
+            .default_value("csharp")
+            .help("Target language(s): csharp, ts, rust, kotlin (comma-separated to emit several)"))
+        .arg(Arg::with_name("schema")
+            .long("schema")
+            .help("Treat the input as a JSON Schema document rather than a sample instance"))
+        .arg(Arg::with_name("casing")
+            .long("casing")
+            .value_name("CASING")
+            .takes_value(true)
+            .possible_values(&["pascal", "camel", "snake", "asis"])
+            .help("Identifier casing for property names (default: whatever's idiomatic for the target language)"))
+        .arg(Arg::with_name("indent")
+            .short("i")
+            .long("indent")
+            .value_name("SPACES")
+            .takes_value(true)
+            .default_value("4")
+            .help("Spaces per indent level"))
         .get_matches();
-    
+
+    let backends: Vec<_> = matches.value_of("lang").unwrap()
+        .split(',')
+        .map(|name| codegen::lookup(name).unwrap_or_else(|| panic!("Unknown target language '{}'.", name)))
+        .collect();
+
+    // possible_values already restricts this to a value Casing::parse accepts;
+    // None means the user didn't pass --casing, so each backend falls back to
+    // its own idiomatic default below.
+    let casing_override = matches.value_of("casing").map(|name| Casing::parse(name).unwrap());
+    let indent_size: usize = matches.value_of("indent").unwrap()
+        .parse()
+        .expect("--indent must be a non-negative integer");
+    let indent = " ".repeat(indent_size);
+
     let input = if let Some(filename) = matches.value_of_os("file") {
         // read from file
         fs::read_to_string(filename)
     } else {
         // read from stdin
         let mut buffer = String::new();
-        io::stdin().read_to_string(&mut buffer).and_then(|_| Ok(buffer))
+        io::stdin().read_to_string(&mut buffer).map(|_| buffer)
     };
-    
+
     // JSON as a string.
     let json = input.expect("Failed to read JSON.");
-    // Abstract syntax tree corresponding to the JSON
+    // Abstract syntax tree corresponding to the JSON (a sample instance,
+    // or a JSON Schema document when --schema is given)
     let ast: Value = serde_json::from_str(&json).expect("Failed to parse JSON.");
-    
-    // generate classes
-    let mut generator = ClassGenerator::new();
-    let classes = generator.generate(&ast).expect("Failed to generate classes.");
-    
-    // print the classes
-    print!("{}", classes);
-}
 
-// ----------------------------------------------------------------------------
-
-struct ClassGenerator<'t> {
-    // queue of classes that are to be generated
-    // each tuple is (<class name>, <AST node representing class properties>)
-    todos: VecDeque<(String, &'t Map<String, Value>)>
-}
-
-impl<'t> ClassGenerator<'t> {
-    fn new() -> Self {
-        Self { todos: VecDeque::new() }
-    }
-    
-    /// Generates the classes corresponding to the given AST.
-    /// Returns the classes as a string.
-    fn generate(&mut self, ast: &'t Value) -> Result<String, Error> {
-        // output builder
-        let mut out = String::new();
-        // root of the AST
-        let root = ast.as_object().ok_or(fmt::Error)?;
-        
-        // generate the Root class. This corresponds to the base { ... } of the JSON.
-        writeln!(&mut out, "public class Root\n{{")?; // class declaration {
-        self.generate_properties(root, &mut out)?;    //   class properties
-        writeln!(&mut out, "}}")?;                    // }
-        
-        // generate all other classes.
-        while let Some((class, node)) = self.todos.pop_front() {
-            writeln!(&mut out, "\npublic class {}\n{{", class)?; // class declaration {
-            self.generate_properties(node, &mut out)?;           //   class properties
-            writeln!(&mut out, "}}")?;                           // }
-        }
-        
-        Ok(out)
-    }
-    
-    /// Generate class properties
-    /// eg. "public int id { get; set; }"
-    fn generate_properties(&mut self, root: &'t Map<String, Value>, out: &mut String) -> Result<(), Error> {
-        for entry in root {
-            let class = self.find_type(entry);
-            let varname = entry.0.to_ascii_lowercase();
-            writeln!(out, "    public {} {} {{ get; set; }}", class, varname)?;
-        }
-        Ok(())
-    }
-    
-    /// Find the concrete C# type corresponding to the JSON value
-    /// given by the AST entry.
-    /// Returns the concrete type as a CSharpType enum value.
-    fn find_type(&mut self, entry: (&String, &'t Value)) -> CSharpType {
-        let value = entry.1;
-        match value {
-            Value::String(_) => CSharpType::Primitive("string"),
-            Value::Number(num) => match num {
-                _ if num.is_i64() => CSharpType::Primitive("int"),
-                _ if num.is_f64() => CSharpType::Primitive("float"),
-                _ => CSharpType::Primitive("uint"),
-            },
-            Value::Bool(_) => CSharpType::Primitive("bool"),
-            Value::Array(_) => CSharpType::Custom(self.flatten_type(entry)),
-            Value::Object(obj) => {
-                let class_name = Self::titlecase(entry.0);
-                // enqueue an entry for this class to our TODOs so we
-                // can later generate its class+properties at a later time.
-                self.todos.push_back((class_name.clone(), obj));
-                CSharpType::Custom(class_name)
-            },
-            Value::Null => unreachable!()
-        }
-    }
-    
-    /// Flatten the array indicated by the given entry in the AST.
-    /// Returns the flattened type as a string.
-    /// Example:
-    ///     "nums": [ [ 14, -3, 8 ] ]  ->  "int[][]"
-    fn flatten_type(&mut self, entry: (&String, &'t Value)) -> String {
-        // Descends the AST until a concrete type is reached (anything other than an array),
-        let mut bracket_count: usize = 0;
-        let mut curr = entry.1;
-        while let Value::Array(a) = curr {
-            curr = a.first().unwrap();
-            bracket_count += 1;
+    let empty_definitions = Map::new();
+    let definitions = ast.as_object()
+        .and_then(|root| root.get("definitions"))
+        .and_then(Value::as_object)
+        .unwrap_or(&empty_definitions);
+
+    // generate and print the classes for each requested language
+    let multiple = backends.len() > 1;
+    for backend in &backends {
+        if multiple {
+            println!("// ---- {} ----", backend.name());
         }
-        // concat the concrete name with the trailing array brackets
-        self.find_type((entry.0, curr))
-            .as_str()
-            .to_owned()
-            + "[]".repeat(bracket_count).as_str()
-    }
-    
-
-    
-    /// Returns a string identical to the given one except that the
-    /// first letter of the returned string will be capitalized.
-    /// eg. "weather" -> "Weather"
-    fn titlecase(name: &str) -> String {
-        let mut res = name.to_owned();
-        if let Some(ch) = res.get_mut(0..1) {
-            ch.make_ascii_uppercase();
-        }
-        res
-    }
-}
-
-// ----------------------------------------------------------------------------
-
-#[cfg(test)]
-mod test {
-    use super::*;
-    use std::fs;
-    
-    #[test]
-    fn test_weather() {
-        let resources = env!("CARGO_MANIFEST_DIR").to_owned() + "/resources/";
-        let input_path = resources.clone() + "weather.json";
-        let answer_path = resources + "weather.cs";
-        
-        let ast =
-            fs::read_to_string(input_path)
-            .map(|weather_json|
-                serde_json::from_str(&weather_json).unwrap()
-            )
-            .unwrap();
-        
-        let mut generator = ClassGenerator::new();
-        let output = generator.generate(&ast).expect("Failed to generate classes.");
-        
-        let answer = fs::read_to_string(answer_path).unwrap();
-        assert!(output.eq(&answer));
+        let casing = casing_override.unwrap_or_else(|| backend.default_casing());
+        let classes = if matches.is_present("schema") {
+            let mut generator = SchemaGenerator::new(definitions);
+            generator.generate(&ast, backend.as_ref(), casing, &indent).expect("Failed to generate classes.")
+        } else {
+            let mut generator = ClassGenerator::new();
+            generator.generate(&ast, backend.as_ref(), casing, &indent).expect("Failed to generate classes.")
+        };
+        print!("{}", classes);
     }
 }
-
-
@@ -0,0 +1,73 @@
+//! Small identifier-naming helpers shared by the instance-based and
+//! schema-based front ends.
+
+use heck::{ToLowerCamelCase, ToPascalCase, ToSnakeCase};
+use std::collections::HashSet;
+
+/// Converts a JSON key/field name into a PascalCase type name, eg. for a
+/// generated class or enum: "user_profile" -> "UserProfile".
+pub fn pascal_case(name: &str) -> String {
+    name.to_pascal_case()
+}
+
+/// Converts an arbitrary schema `"enum"` value into a valid PascalCase enum
+/// variant identifier, eg. "date-time" -> "DateTime". Values that would
+/// start with a digit once cased (identifiers can't) are given a leading
+/// `V`, eg. "1" -> "V1".
+pub fn sanitize_enum_variant(raw: &str) -> String {
+    let cased = raw.to_pascal_case();
+    match cased.chars().next() {
+        Some(ch) if ch.is_ascii_digit() => format!("V{}", cased),
+        Some(_) => cased,
+        None => "Empty".to_owned(),
+    }
+}
+
+/// Disambiguates `desired` against names already claimed in `used` by
+/// appending a numeric suffix (`Data`, `Data2`, ...), the same scheme
+/// `ClassGenerator::intern_shape` uses for colliding class names. Inserts
+/// the chosen name into `used` before returning it.
+pub fn disambiguate(used: &mut HashSet<String>, desired: &str) -> String {
+    let mut name = desired.to_owned();
+    let mut suffix = 2;
+    while used.contains(&name) {
+        name = format!("{}{}", desired, suffix);
+        suffix += 1;
+    }
+    used.insert(name.clone());
+    name
+}
+
+/// The identifier casing to apply to a JSON key when emitting a property
+/// name, selected via `--casing`.
+#[derive(Clone, Copy)]
+pub enum Casing {
+    Pascal,
+    Camel,
+    Snake,
+    /// Keep the original JSON key exactly as-is.
+    AsIs,
+}
+
+impl Casing {
+    /// Parses a `--casing` value, eg. "pascal", "camel", "snake", "asis".
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "pascal" => Some(Casing::Pascal),
+            "camel" => Some(Casing::Camel),
+            "snake" => Some(Casing::Snake),
+            "asis" | "as-is" => Some(Casing::AsIs),
+            _ => None,
+        }
+    }
+
+    /// Applies this casing to a JSON key, producing the property name.
+    pub fn apply(&self, key: &str) -> String {
+        match self {
+            Casing::Pascal => key.to_pascal_case(),
+            Casing::Camel => key.to_lower_camel_case(),
+            Casing::Snake => key.to_snake_case(),
+            Casing::AsIs => key.to_owned(),
+        }
+    }
+}
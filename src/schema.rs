@@ -0,0 +1,273 @@
+//! Generates classes from a JSON Schema document instead of a sample JSON
+//! instance.
+//!
+//! Unlike `codegen::ClassGenerator`, which infers types from a single
+//! example value, `SchemaGenerator` reads declared types directly off the
+//! schema: `"properties"` gives field names, `"$ref"` pointers resolve to
+//! named classes instead of invented ones, `"required"` decides
+//! nullability, `"enum"` becomes a generated enum type, and `"format"`
+//! hints (`"date-time"`, `"uuid"`) refine the primitive mapping.
+
+use crate::codegen::CodeGen;
+use crate::naming::{pascal_case, sanitize_enum_variant, Casing};
+use serde_json::{Value, Map};
+use std::collections::{HashSet, VecDeque};
+use std::fmt::{self, Write, Error};
+
+pub struct SchemaGenerator<'t> {
+    // "definitions" from the schema document, used to resolve $ref pointers.
+    definitions: &'t Map<String, Value>,
+    // queue of classes still to be generated, each a (class name, object
+    // schema node) pair.
+    todos: VecDeque<(String, &'t Map<String, Value>)>,
+    // $ref names already queued/resolved, so a recursive or repeated
+    // reference doesn't enqueue (or loop) more than once.
+    queued_refs: HashSet<String>,
+    // enum types discovered while walking properties, written out after
+    // all the classes. Each variant is (sanitized identifier, raw value).
+    enums: Vec<(String, Vec<(String, String)>)>,
+}
+
+impl<'t> SchemaGenerator<'t> {
+    pub fn new(definitions: &'t Map<String, Value>) -> Self {
+        Self {
+            definitions,
+            todos: VecDeque::new(),
+            queued_refs: HashSet::new(),
+            enums: Vec::new(),
+        }
+    }
+
+    /// Generates the classes corresponding to the given schema using
+    /// `backend`, naming properties per `casing` and indenting
+    /// property/enum lines by `indent`.
+    /// Returns the classes as a string.
+    pub fn generate(&mut self, schema: &'t Value, backend: &dyn CodeGen, casing: Casing, indent: &str) -> Result<String, Error> {
+        let mut out = String::new();
+        let root = schema.as_object().ok_or(fmt::Error)?;
+
+        backend.begin_class(&mut out, "Root")?;
+        self.generate_properties(root, &mut out, backend, casing, indent)?;
+        backend.end_class(&mut out)?;
+
+        while let Some((class, node)) = self.todos.pop_front() {
+            writeln!(&mut out)?;
+            backend.begin_class(&mut out, &class)?;
+            self.generate_properties(node, &mut out, backend, casing, indent)?;
+            backend.end_class(&mut out)?;
+        }
+
+        for (name, variants) in &self.enums {
+            writeln!(&mut out)?;
+            backend.write_enum(&mut out, name, variants, indent)?;
+        }
+
+        Ok(out)
+    }
+
+    /// Generate class properties from an object schema's "properties" map,
+    /// honoring its "required" list.
+    fn generate_properties(&mut self, schema: &'t Map<String, Value>, out: &mut String, backend: &dyn CodeGen, casing: Casing, indent: &str) -> Result<(), Error> {
+        let properties = match schema.get("properties").and_then(Value::as_object) {
+            Some(properties) => properties,
+            None => return Ok(()),
+        };
+        let required: HashSet<&str> = schema.get("required")
+            .and_then(Value::as_array)
+            .map(|names| names.iter().filter_map(Value::as_str).collect())
+            .unwrap_or_default();
+
+        // casing can collapse distinct keys onto the same identifier (eg.
+        // "Data" and "data" both becoming "Data"), so disambiguate per class.
+        let mut used_names = HashSet::new();
+        for (name, subschema) in properties {
+            let typename = self.resolve_type(name, subschema, backend);
+            let typename = if required.contains(name.as_str()) {
+                typename
+            } else {
+                backend.nullable_type(&typename)
+            };
+            let varname = crate::naming::disambiguate(&mut used_names, &casing.apply(name));
+            if varname != *name {
+                backend.write_key_annotation(out, indent, name)?;
+            }
+            backend.write_property(out, &varname, &typename, indent)?;
+        }
+        Ok(())
+    }
+
+    /// Resolve the type of a single schema node, enqueuing any nested
+    /// class/enum definitions it introduces along the way.
+    fn resolve_type(&mut self, field_name: &str, node: &'t Value, backend: &dyn CodeGen) -> String {
+        let node = match node.as_object() {
+            Some(node) => node,
+            None => return backend.string_type().to_owned(),
+        };
+
+        if let Some(ref_path) = node.get("$ref").and_then(Value::as_str) {
+            let ref_name = ref_path.rsplit('/').next().unwrap_or(ref_path).to_owned();
+            return match self.definitions.get(&ref_name).and_then(Value::as_object) {
+                Some(target) => {
+                    if self.queued_refs.insert(ref_name.clone()) {
+                        self.todos.push_back((ref_name.clone(), target));
+                    }
+                    ref_name
+                }
+                // dangling reference: the definition doesn't exist, so don't
+                // emit a type that points at a class we'll never generate.
+                None => backend.object_type().to_owned(),
+            };
+        }
+
+        if let Some(variants) = node.get("enum").and_then(Value::as_array) {
+            let enum_name = pascal_case(field_name);
+            let variants = variants.iter().filter_map(Value::as_str)
+                .map(|raw| (sanitize_enum_variant(raw), raw.to_owned()))
+                .collect();
+            self.enums.push((enum_name.clone(), variants));
+            return enum_name;
+        }
+
+        if let Some(format) = node.get("format").and_then(Value::as_str) {
+            match format {
+                "date-time" => return backend.date_time_type().to_owned(),
+                "uuid" => return backend.uuid_type().to_owned(),
+                _ => {} // unrecognized format, fall through to the base "type"
+            }
+        }
+
+        match node.get("type").and_then(Value::as_str) {
+            Some("integer") => backend.int_type().to_owned(),
+            Some("number") => backend.float_type().to_owned(),
+            Some("boolean") => backend.bool_type().to_owned(),
+            Some("array") => {
+                let item_type = node.get("items")
+                    .map(|items| self.resolve_type(field_name, items, backend))
+                    .unwrap_or_else(|| backend.string_type().to_owned());
+                backend.array_type(&item_type)
+            }
+            Some("object") => {
+                let class_name = pascal_case(field_name);
+                self.todos.push_back((class_name.clone(), node));
+                class_name
+            }
+            // Schemas are allowed to omit "type" entirely; an inline object
+            // subschema is recognizable by having a "properties" map even
+            // without an explicit "type": "object".
+            _ if node.contains_key("properties") => {
+                let class_name = pascal_case(field_name);
+                self.todos.push_back((class_name.clone(), node));
+                class_name
+            }
+            // "string", or genuinely untyped (no "type", no "properties").
+            _ => backend.string_type().to_owned(),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::codegen::CSharp;
+    use serde_json::json;
+
+    #[test]
+    fn test_ref_and_required() {
+        let schema = json!({
+            "definitions": {
+                "Address": {
+                    "type": "object",
+                    "properties": {
+                        "city": {"type": "string"}
+                    },
+                    "required": ["city"]
+                }
+            },
+            "properties": {
+                "id": {"type": "integer"},
+                "home": {"$ref": "#/definitions/Address"}
+            },
+            "required": ["id"]
+        });
+        let definitions = schema["definitions"].as_object().unwrap();
+
+        let mut generator = SchemaGenerator::new(definitions);
+        let output = generator.generate(&schema, &CSharp, Casing::Pascal, "    ")
+            .expect("Failed to generate classes.");
+
+        // required field is emitted as-is; optional field is wrapped nullable
+        assert!(output.contains("public int Id { get; set; }"));
+        assert!(output.contains("public Address? Home { get; set; }"));
+        assert!(output.contains("public class Address"));
+        assert!(output.contains("public string City { get; set; }"));
+    }
+
+    #[test]
+    fn test_dangling_ref_falls_back_to_object_type() {
+        let schema = json!({
+            "properties": {
+                "home": {"$ref": "#/definitions/DoesNotExist"}
+            }
+        });
+        let empty_definitions = Map::new();
+
+        let mut generator = SchemaGenerator::new(&empty_definitions);
+        let output = generator.generate(&schema, &CSharp, Casing::Pascal, "    ")
+            .expect("Failed to generate classes.");
+
+        // no class was ever queued for the missing definition
+        assert!(!output.contains("public class DoesNotExist"));
+        assert!(output.contains("public object? Home { get; set; }"));
+    }
+
+    #[test]
+    fn test_enum_and_format() {
+        let schema = json!({
+            "properties": {
+                "createdAt": {"type": "string", "format": "date-time"},
+                "status": {"enum": ["active", "date-time"]}
+            },
+            "required": ["createdAt", "status"]
+        });
+        let empty_definitions = Map::new();
+
+        let mut generator = SchemaGenerator::new(&empty_definitions);
+        let output = generator.generate(&schema, &CSharp, Casing::Pascal, "    ")
+            .expect("Failed to generate classes.");
+
+        assert!(output.contains("public DateTime CreatedAt { get; set; }"));
+        assert!(output.contains("public Status Status { get; set; }"));
+        assert!(output.contains("public enum Status"));
+        // "date-time" isn't a valid bare identifier; it's sanitized to PascalCase
+        assert!(output.contains("DateTime,"));
+        assert!(!output.contains("date-time,"));
+    }
+
+    #[test]
+    fn test_inline_object_without_explicit_type() {
+        let schema = json!({
+            "properties": {
+                "address": {
+                    "properties": {
+                        "city": {"type": "string"}
+                    },
+                    "required": ["city"]
+                }
+            },
+            "required": ["address"]
+        });
+        let empty_definitions = Map::new();
+
+        let mut generator = SchemaGenerator::new(&empty_definitions);
+        let output = generator.generate(&schema, &CSharp, Casing::Pascal, "    ")
+            .expect("Failed to generate classes.");
+
+        // even without "type": "object", a "properties" map is enough to
+        // recognize an inline object subschema
+        assert!(output.contains("public Address Address { get; set; }"));
+        assert!(output.contains("public class Address"));
+        assert!(output.contains("public string City { get; set; }"));
+    }
+}